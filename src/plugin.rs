@@ -1,21 +1,33 @@
 use arrayref::array_ref;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use pulsar::authentication::oauth2::{OAuth2Authentication, OAuth2Params};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs::File;
 use std::str::FromStr;
-use std::sync::mpsc::{channel, Sender};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use {
     agave_geyser_plugin_interface::geyser_plugin_interface::{
-        GeyserPlugin, ReplicaAccountInfoVersions, Result as PluginResult,
+        GeyserPlugin, ReplicaAccountInfoVersions, ReplicaTransactionInfoVersions,
+        Result as PluginResult, SlotStatus,
     },
     pulsar::{Producer, Pulsar, TokioExecutor},
+    solana_account_decoder::UiAccountEncoding,
+    solana_client::{
+        nonblocking::rpc_client::RpcClient,
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+    },
     solana_program::pubkey::Pubkey,
+    solana_sdk::commitment_config::CommitmentConfig,
 };
 
 // Message type for our channel
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 enum PulsarMessage {
     AccountUpdate {
         pubkey: Pubkey,
@@ -23,15 +35,318 @@ enum PulsarMessage {
         account_data: Vec<u8>,
         write_version: u64,
         slot: u64,
+        topics: Vec<String>,
+        is_snapshot: bool,
+    },
+    SlotUpdate {
+        slot: u64,
+        parent: Option<u64>,
+        status: SlotStatus,
+        topic: String,
+    },
+    // Drives the per-slot Pulsar transaction: buffered AccountUpdate
+    // messages for `slot` are committed atomically once it reaches
+    // Confirmed/Rooted, and messages for any `abandoned_slots` (forked away
+    // by this slot becoming Rooted) are discarded instead of published.
+    SlotTransaction {
+        slot: u64,
+        status: SlotStatus,
+        abandoned_slots: Vec<u64>,
+    },
+    Transaction {
+        signature: String,
+        slot: u64,
+        account_keys: Vec<Pubkey>,
+        is_success: bool,
+        topic: String,
     },
     Shutdown,
 }
 
+// Rooted slot boundaries and control messages are never safe to discard: a
+// rooted SlotTransaction is what actually commits a slot's buffered updates,
+// and Shutdown must always be delivered. Everything else is fair game for
+// the DropOldest overflow policy to evict.
+fn is_evictable(msg: &PulsarMessage) -> bool {
+    !matches!(
+        msg,
+        PulsarMessage::Shutdown
+            | PulsarMessage::SlotTransaction {
+                status: SlotStatus::Rooted,
+                ..
+            }
+    )
+}
+
+// Slot that a dropped message counts towards for the purposes of deciding
+// whether a later SlotTransaction can still be trusted as "complete". Only
+// messages that feed `pending_by_slot` in the Pulsar worker matter here:
+// live (non-snapshot) AccountUpdates.
+fn evicted_message_slot(msg: &PulsarMessage) -> Option<u64> {
+    match msg {
+        PulsarMessage::AccountUpdate {
+            slot,
+            is_snapshot: false,
+            ..
+        } => Some(*slot),
+        _ => None,
+    }
+}
+
+// A bounded mpsc-style queue that, unlike `std::sync::mpsc::sync_channel`,
+// can evict an already-buffered entry to make room for a new one. This is
+// what lets `ChannelOverflowPolicy::DropOldest` actually drop the oldest
+// non-rooted update instead of rejecting the newest one.
+#[derive(Debug)]
+struct BoundedChannel {
+    capacity: usize,
+    queue: Mutex<VecDeque<PulsarMessage>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    // Slots that had a live AccountUpdate evicted before the worker could
+    // act on it, or dropped on arrival because nothing buffered was
+    // evictable. A slot left in here means the
+    // worker's `pending_by_slot` entry for it is missing data it promised
+    // to publish, so the eventual SlotTransaction for that slot must not be
+    // committed as a complete, atomic transaction.
+    dropped_slots: Mutex<HashSet<u64>>,
+}
+
+impl BoundedChannel {
+    fn new(capacity: usize) -> Self {
+        BoundedChannel {
+            capacity,
+            queue: Mutex::new(VecDeque::new()),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            dropped_slots: Mutex::new(HashSet::new()),
+        }
+    }
+
+    // Blocks until there is room, applying backpressure to the caller.
+    fn send_blocking(&self, msg: PulsarMessage) {
+        let mut queue = self.queue.lock().expect("channel lock should not be poisoned");
+        while queue.len() >= self.capacity {
+            queue = self
+                .not_full
+                .wait(queue)
+                .expect("channel lock should not be poisoned");
+        }
+        queue.push_back(msg);
+        self.not_empty.notify_one();
+    }
+
+    // When full, evicts the oldest evictable (non-rooted) buffered message to
+    // make room for `msg`. If nothing buffered is safe to evict, `msg` itself
+    // is dropped instead. Returns true if anything was dropped.
+    fn send_drop_oldest(&self, msg: PulsarMessage) -> bool {
+        let mut queue = self.queue.lock().expect("channel lock should not be poisoned");
+
+        if queue.len() < self.capacity {
+            queue.push_back(msg);
+            self.not_empty.notify_one();
+            return false;
+        }
+
+        match queue.iter().position(is_evictable) {
+            Some(idx) => {
+                let evicted = queue.remove(idx).expect("idx came from this queue");
+                if let Some(slot) = evicted_message_slot(&evicted) {
+                    self.dropped_slots
+                        .lock()
+                        .expect("channel lock should not be poisoned")
+                        .insert(slot);
+                }
+                queue.push_back(msg);
+                self.not_empty.notify_one();
+                true
+            }
+            None => {
+                if let Some(slot) = evicted_message_slot(&msg) {
+                    self.dropped_slots
+                        .lock()
+                        .expect("channel lock should not be poisoned")
+                        .insert(slot);
+                }
+                true
+            }
+        }
+    }
+
+    // Returns true, and clears the flag, if a message for `slot` was
+    // evicted or dropped at some point before this call. The SlotTransaction
+    // handler consults this to avoid committing a slot's Pulsar transaction
+    // as complete when it no longer has all of its buffered updates.
+    fn take_dropped_slot(&self, slot: u64) -> bool {
+        self.dropped_slots
+            .lock()
+            .expect("channel lock should not be poisoned")
+            .remove(&slot)
+    }
+
+    fn recv(&self) -> PulsarMessage {
+        let mut queue = self.queue.lock().expect("channel lock should not be poisoned");
+        while queue.is_empty() {
+            queue = self
+                .not_empty
+                .wait(queue)
+                .expect("channel lock should not be poisoned");
+        }
+        let msg = queue.pop_front().expect("queue should be non-empty");
+        self.not_full.notify_one();
+        msg
+    }
+}
+
+// Tracks the winning write per account across competing forks so that a
+// consumer only ever sees the latest write for an account at a rooted slot,
+// never a stale or since-abandoned one.
+#[derive(Debug)]
+struct AccountWrite {
+    slot: u64,
+    write_version: u64,
+    data: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct SlotInfo {
+    parent: Option<u64>,
+    status: SlotStatus,
+}
+
+#[derive(Debug, Default)]
+struct ChainData {
+    accounts: HashMap<Pubkey, AccountWrite>,
+    slots: HashMap<u64, SlotInfo>,
+}
+
+impl ChainData {
+    // Returns true if this write is newer than what we have on record for the
+    // account and should be forwarded, recording it as the new latest write.
+    fn observe_account_write(
+        &mut self,
+        pubkey: Pubkey,
+        slot: u64,
+        write_version: u64,
+        data: &[u8],
+    ) -> bool {
+        if let Some(existing) = self.accounts.get(&pubkey) {
+            let is_newer = match slot.cmp(&existing.slot) {
+                std::cmp::Ordering::Less => false,
+                std::cmp::Ordering::Equal => write_version > existing.write_version,
+                std::cmp::Ordering::Greater => true,
+            };
+            if !is_newer {
+                return false;
+            }
+        }
+
+        self.accounts.insert(
+            pubkey,
+            AccountWrite {
+                slot,
+                write_version,
+                data: data.to_vec(),
+            },
+        );
+        true
+    }
+
+    // Returns the slots abandoned by this update, i.e. forked-away slots
+    // whose buffered writes must never be published.
+    fn observe_slot_status(
+        &mut self,
+        slot: u64,
+        parent: Option<u64>,
+        status: SlotStatus,
+    ) -> Vec<u64> {
+        self.slots.insert(slot, SlotInfo { parent, status });
+
+        if status == SlotStatus::Rooted {
+            self.prune_non_ancestors(slot)
+        } else {
+            Vec::new()
+        }
+    }
+
+    // Discards account writes and slot bookkeeping for slots that are not on
+    // the rooted chain, i.e. writes from forks that lost and will never be
+    // published: slots at or below the root that are not its ancestors, and
+    // slots above it whose parent chain drops below the root without passing
+    // through it. Slots above the root whose parent chain is not known are
+    // kept. Returns the abandoned slots.
+    fn prune_non_ancestors(&mut self, rooted_slot: u64) -> Vec<u64> {
+        let mut ancestors = HashSet::new();
+        let mut current = Some(rooted_slot);
+        while let Some(slot) = current {
+            ancestors.insert(slot);
+            current = self.slots.get(&slot).and_then(|info| info.parent);
+        }
+
+        let slots = &self.slots;
+        let is_abandoned = |slot: u64| -> bool {
+            if slot <= rooted_slot {
+                return !ancestors.contains(&slot);
+            }
+            let mut current = slot;
+            loop {
+                match slots.get(&current).and_then(|info| info.parent) {
+                    Some(parent) if parent == rooted_slot => return false,
+                    Some(parent) if parent < rooted_slot => return true,
+                    Some(parent) => current = parent,
+                    None => return false,
+                }
+            }
+        };
+
+        let abandoned_slots: Vec<u64> = slots
+            .keys()
+            .copied()
+            .filter(|slot| is_abandoned(*slot))
+            .collect();
+
+        self.accounts.retain(|_, write| !is_abandoned(write.slot));
+        self.slots.retain(|slot, _| !abandoned_slots.contains(slot));
+
+        abandoned_slots
+    }
+}
+
+// How to handle the channel to the Pulsar worker thread filling up, analogous
+// to the accountsdb service's broadcast/subscriber buffer sizing knobs.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelOverflowPolicy {
+    // Block the caller (update_account et al.) until space frees up.
+    Block,
+    // Evict the oldest buffered non-rooted update to make room and bump the
+    // dropped-message counter, instead of blocking the validator's
+    // replication thread.
+    DropOldest,
+}
+
+impl Default for ChannelOverflowPolicy {
+    fn default() -> Self {
+        ChannelOverflowPolicy::Block
+    }
+}
+
+fn default_channel_buffer_size() -> usize {
+    10_000
+}
+
 #[derive(Debug)]
 pub struct CarrotPlugin {
-    sender: Option<Sender<PulsarMessage>>,
+    sender: Option<Arc<BoundedChannel>>,
     pulsar_handle: Option<thread::JoinHandle<()>>,
-    token_account_filter: Option<TokenAccountFilter>,
+    token_account_filters: Vec<TokenAccountFilter>,
+    slot_topic: Option<String>,
+    transaction_topic: Option<String>,
+    transaction_account_filter: HashSet<Pubkey>,
+    chain_data: Mutex<ChainData>,
+    highest_write_slot: AtomicU64,
+    overflow_policy: ChannelOverflowPolicy,
+    dropped_messages: AtomicU64,
 }
 
 impl Default for CarrotPlugin {
@@ -39,7 +354,14 @@ impl Default for CarrotPlugin {
         CarrotPlugin {
             sender: None,
             pulsar_handle: None,
-            token_account_filter: None,
+            token_account_filters: Vec::new(),
+            slot_topic: None,
+            transaction_topic: None,
+            transaction_account_filter: HashSet::new(),
+            chain_data: Mutex::new(ChainData::default()),
+            highest_write_slot: AtomicU64::new(0),
+            overflow_policy: ChannelOverflowPolicy::Block,
+            dropped_messages: AtomicU64::new(0),
         }
     }
 }
@@ -51,7 +373,7 @@ pub struct StreamNativeOAuth2Config {
     pub audience: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct TokenAccountFilter {
     pub mint_address: String,
     pub token_program: String,
@@ -62,7 +384,14 @@ pub struct TokenAccountFilter {
 pub struct CarrotPluginConfig {
     pub pulsar_url: String,
     pub streamnative_oauth2: StreamNativeOAuth2Config,
-    pub token_account_filter: TokenAccountFilter,
+    pub filters: Vec<TokenAccountFilter>,
+    pub slot_topic: Option<String>,
+    pub transaction_topic: Option<String>,
+    pub rpc_http_url: Option<String>,
+    #[serde(default = "default_channel_buffer_size")]
+    pub channel_buffer_size: usize,
+    #[serde(default)]
+    pub channel_overflow_policy: ChannelOverflowPolicy,
 }
 
 pub struct PulsarConfig {
@@ -70,18 +399,58 @@ pub struct PulsarConfig {
     pub issuer_url: String,
     pub credentials_url: String,
     pub audience: String,
+    pub topics: Vec<String>,
+}
+
+fn slot_status_str(status: &SlotStatus) -> &'static str {
+    match status {
+        SlotStatus::Processed => "processed",
+        SlotStatus::Confirmed => "confirmed",
+        SlotStatus::Rooted => "rooted",
+    }
 }
 
 impl CarrotPlugin {
-    fn start_pulsar_client(&mut self, config: PulsarConfig) -> PluginResult<()> {
-        let (sender, receiver) = channel();
-        self.sender = Some(sender);
-        let token_account_filter = self
-            .token_account_filter
-            .as_ref()
-            .expect("should access token account filter")
-            .topic
-            .clone();
+    // Exposes the highest slot a write matching one of the configured filters
+    // has been observed for, so callers can
+    // distinguish startup replay from live writes.
+    pub fn highest_write_slot(&self) -> u64 {
+        self.highest_write_slot.load(Ordering::Relaxed)
+    }
+
+    // Count of updates dropped because the channel to the Pulsar worker
+    // thread was full and `ChannelOverflowPolicy::DropOldest` is in effect.
+    pub fn dropped_messages(&self) -> u64 {
+        self.dropped_messages.load(Ordering::Relaxed)
+    }
+
+    // Sends a message to the Pulsar worker thread honoring the configured
+    // overflow policy: block for backpressure, or evict the oldest buffered
+    // non-rooted update and count it for DropOldest so a stalled Pulsar
+    // connection can't OOM the validator while still serving the freshest
+    // state.
+    fn dispatch(&self, msg: PulsarMessage) {
+        let Some(channel) = &self.sender else {
+            return;
+        };
+
+        match self.overflow_policy {
+            ChannelOverflowPolicy::Block => channel.send_blocking(msg),
+            ChannelOverflowPolicy::DropOldest => {
+                if channel.send_drop_oldest(msg) {
+                    self.dropped_messages.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    fn start_pulsar_client(
+        &mut self,
+        config: PulsarConfig,
+        channel_buffer_size: usize,
+    ) -> PluginResult<()> {
+        let channel = Arc::new(BoundedChannel::new(channel_buffer_size));
+        self.sender = Some(channel.clone());
 
         let handle = thread::spawn(move || {
             // Create a new tokio runtime for this thread
@@ -100,21 +469,38 @@ impl CarrotPlugin {
 
                 let pulsar = builder.build().await.expect("should connect to Pulsar");
 
-                // Create producers for each topic
-                let mut producer = pulsar
-                    .producer()
-                    .with_topic(token_account_filter)
-                    .build()
-                    .await
-                    .expect("should create producer");
+                // Create one producer per distinct topic so a single account update
+                // can be fanned out to several topics (e.g. per-mint streams).
+                let mut producers: HashMap<String, Producer<TokioExecutor>> = HashMap::new();
+                for topic in config.topics {
+                    if producers.contains_key(&topic) {
+                        continue;
+                    }
+                    let producer = pulsar
+                        .producer()
+                        .with_topic(topic.clone())
+                        .build()
+                        .await
+                        .expect("should create producer");
 
-                producer
-                    .check_connection()
-                    .await
-                    .expect("should be able to connect to pulsar");
+                    producer
+                        .check_connection()
+                        .await
+                        .expect("should be able to connect to pulsar");
+
+                    producers.insert(topic, producer);
+                }
+
+                // Live AccountUpdate messages are buffered per slot here and
+                // only committed to Pulsar as one transaction once the slot
+                // reaches Confirmed/Rooted, so consumers get atomic,
+                // all-or-nothing slot boundaries. Snapshot messages bypass
+                // this buffering since they aren't tied to a live slot.
+                let mut pending_by_slot: HashMap<u64, Vec<(String, Vec<u8>)>> = HashMap::new();
 
                 // Process messages from the channel
-                while let Ok(msg) = receiver.recv() {
+                loop {
+                    let msg = channel.recv();
                     match msg {
                         PulsarMessage::AccountUpdate {
                             pubkey,
@@ -122,6 +508,8 @@ impl CarrotPlugin {
                             token_account_owner,
                             account_data,
                             write_version,
+                            topics,
+                            is_snapshot,
                         } => {
                             // Create message payload
                             let payload = serde_json::json!({
@@ -130,14 +518,158 @@ impl CarrotPlugin {
                                 "token_account_owner": token_account_owner,
                                 "account_data": account_data,
                                 "write_version": write_version,
+                                "is_snapshot": is_snapshot,
                             });
+                            let bytes = payload.to_string().into_bytes();
+
+                            if is_snapshot {
+                                for topic in topics {
+                                    let Some(producer) = producers.get_mut(&topic) else {
+                                        eprintln!("No producer configured for topic: {}", topic);
+                                        continue;
+                                    };
+
+                                    if let Err(e) =
+                                        producer.send_non_blocking(bytes.clone()).await
+                                    {
+                                        eprintln!("Failed to send message to Pulsar: {:?}", e);
+                                    }
+                                }
+                            } else {
+                                let buffered = pending_by_slot.entry(slot).or_default();
+                                for topic in topics {
+                                    buffered.push((topic, bytes.clone()));
+                                }
+                            }
+                        }
+                        PulsarMessage::SlotUpdate {
+                            slot,
+                            parent,
+                            status,
+                            topic,
+                        } => {
+                            let payload = serde_json::json!({
+                                "slot": slot,
+                                "parent": parent,
+                                "status": slot_status_str(&status),
+                            });
+
+                            let Some(producer) = producers.get_mut(&topic) else {
+                                eprintln!("No producer configured for topic: {}", topic);
+                                continue;
+                            };
 
-                            // Send to Pulsar
                             if let Err(e) = producer
                                 .send_non_blocking(payload.to_string().into_bytes())
                                 .await
                             {
-                                eprintln!("Failed to send message to Pulsar: {:?}", e);
+                                eprintln!("Failed to send slot update to Pulsar: {:?}", e);
+                            }
+                        }
+                        PulsarMessage::SlotTransaction {
+                            slot,
+                            status,
+                            abandoned_slots,
+                        } => {
+                            if matches!(status, SlotStatus::Confirmed | SlotStatus::Rooted) {
+                                let slot_incomplete = channel.take_dropped_slot(slot);
+                                if let Some(messages) = pending_by_slot.remove(&slot) {
+                                    if slot_incomplete {
+                                        eprintln!(
+                                            "Discarding {} buffered update(s) for slot {}: one or more updates for this slot were evicted by the channel overflow policy, so it can no longer be published as a complete transaction",
+                                            messages.len(),
+                                            slot
+                                        );
+                                    } else if !messages.is_empty() {
+                                        match pulsar.new_transaction().build().await {
+                                            Ok(txn) => {
+                                                let mut all_sent = true;
+                                                for (topic, bytes) in &messages {
+                                                    let Some(producer) =
+                                                        producers.get_mut(topic)
+                                                    else {
+                                                        eprintln!(
+                                                            "No producer configured for topic: {}",
+                                                            topic
+                                                        );
+                                                        all_sent = false;
+                                                        continue;
+                                                    };
+
+                                                    if let Err(e) = producer
+                                                        .send_with_txn(&txn, bytes.clone())
+                                                        .await
+                                                    {
+                                                        eprintln!(
+                                                            "Failed to send message within Pulsar transaction for slot {}: {:?}",
+                                                            slot, e
+                                                        );
+                                                        all_sent = false;
+                                                    }
+                                                }
+
+                                                if all_sent {
+                                                    if let Err(e) = txn.commit().await {
+                                                        eprintln!(
+                                                            "Failed to commit Pulsar transaction for slot {}: {:?}",
+                                                            slot, e
+                                                        );
+                                                    }
+                                                } else if let Err(e) = txn.abort().await {
+                                                    eprintln!(
+                                                        "Failed to abort Pulsar transaction for slot {}: {:?}",
+                                                        slot, e
+                                                    );
+                                                }
+                                            }
+                                            Err(e) => eprintln!(
+                                                "Failed to start Pulsar transaction for slot {}: {:?}",
+                                                slot, e
+                                            ),
+                                        }
+                                    }
+                                }
+                            }
+
+                            // Forked-away slots never reached Confirmed on this
+                            // chain, so their buffered updates are discarded
+                            // rather than published.
+                            for abandoned_slot in abandoned_slots {
+                                if pending_by_slot.remove(&abandoned_slot).is_some() {
+                                    println!(
+                                        "Discarding buffered updates for abandoned fork slot {}",
+                                        abandoned_slot
+                                    );
+                                }
+                            }
+                        }
+                        PulsarMessage::Transaction {
+                            signature,
+                            slot,
+                            account_keys,
+                            is_success,
+                            topic,
+                        } => {
+                            let payload = serde_json::json!({
+                                "signature": signature,
+                                "slot": slot,
+                                "account_keys": account_keys
+                                    .iter()
+                                    .map(|key| key.to_string())
+                                    .collect::<Vec<String>>(),
+                                "is_success": is_success,
+                            });
+
+                            let Some(producer) = producers.get_mut(&topic) else {
+                                eprintln!("No producer configured for topic: {}", topic);
+                                continue;
+                            };
+
+                            if let Err(e) = producer
+                                .send_non_blocking(payload.to_string().into_bytes())
+                                .await
+                            {
+                                eprintln!("Failed to send transaction to Pulsar: {:?}", e);
                             }
                         }
                         PulsarMessage::Shutdown => break,
@@ -149,6 +681,86 @@ impl CarrotPlugin {
         self.pulsar_handle = Some(handle);
         Ok(())
     }
+
+    // Fetches the currently matching token accounts for each configured
+    // filter via getProgramAccounts and publishes them as snapshot
+    // AccountUpdate messages before live updates start flowing.
+    fn start_snapshot_bootstrap(&self, rpc_http_url: String, filters: Vec<TokenAccountFilter>) {
+        let Some(channel) = self.sender.clone() else {
+            return;
+        };
+
+        thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+
+            rt.block_on(async move {
+                let rpc_client = RpcClient::new_with_commitment(
+                    rpc_http_url,
+                    CommitmentConfig::finalized(),
+                );
+
+                // Accounts returned by getProgramAccounts don't carry a slot
+                // themselves, so stamp the whole snapshot with the slot we
+                // fetched it at.
+                let context_slot = rpc_client
+                    .get_slot_with_commitment(CommitmentConfig::finalized())
+                    .await
+                    .unwrap_or(0);
+
+                for filter in filters {
+                    let token_program_pubkey = Pubkey::from_str(&filter.token_program)
+                        .expect("should parse token program pubkey");
+                    let mint_pubkey = Pubkey::from_str(&filter.mint_address)
+                        .expect("should parse mint address pubkey");
+                    let mint_bytes_base64 = STANDARD.encode(mint_pubkey.to_bytes());
+
+                    let config = RpcProgramAccountsConfig {
+                        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new(
+                            0,
+                            MemcmpEncodedBytes::Base64(mint_bytes_base64),
+                        ))]),
+                        account_config: RpcAccountInfoConfig {
+                            encoding: Some(UiAccountEncoding::Base64),
+                            commitment: Some(CommitmentConfig::finalized()),
+                            ..RpcAccountInfoConfig::default()
+                        },
+                        ..RpcProgramAccountsConfig::default()
+                    };
+
+                    let response = match rpc_client
+                        .get_program_accounts_with_config(&token_program_pubkey, config)
+                        .await
+                    {
+                        Ok(response) => response,
+                        Err(e) => {
+                            eprintln!("Failed to fetch snapshot for {}: {:?}", filter.topic, e);
+                            continue;
+                        }
+                    };
+
+                    for (pubkey, account) in response {
+                        if account.data.len() < 64 {
+                            continue;
+                        }
+
+                        let token_account_owner_bytes = array_ref![account.data, 32, 32];
+                        let token_account_owner =
+                            Pubkey::new_from_array(*token_account_owner_bytes);
+
+                        channel.send_blocking(PulsarMessage::AccountUpdate {
+                            pubkey,
+                            slot: context_slot,
+                            token_account_owner,
+                            account_data: account.data,
+                            write_version: 0,
+                            topics: vec![filter.topic.clone()],
+                            is_snapshot: true,
+                        });
+                    }
+                }
+            });
+        });
+    }
 }
 
 impl GeyserPlugin for CarrotPlugin {
@@ -162,22 +774,64 @@ impl GeyserPlugin for CarrotPlugin {
         let config: CarrotPluginConfig =
             serde_json::from_reader(&mut config_file).expect("should be able to parse config file");
 
+        let mut topics: Vec<String> = config
+            .filters
+            .iter()
+            .map(|filter| filter.topic.clone())
+            .collect();
+        if let Some(slot_topic) = &config.slot_topic {
+            topics.push(slot_topic.clone());
+        }
+        if let Some(transaction_topic) = &config.transaction_topic {
+            topics.push(transaction_topic.clone());
+        }
+
         let pulsar_config = PulsarConfig {
             pulsar_url: config.pulsar_url,
             issuer_url: config.streamnative_oauth2.issuer_url,
             credentials_url: config.streamnative_oauth2.credentials_url,
             audience: config.streamnative_oauth2.audience,
+            topics,
         };
 
-        self.token_account_filter = Some(config.token_account_filter);
+        // Transactions are only forwarded when they touch one of the configured
+        // mints or token programs, so build the lookup set once up front.
+        self.transaction_account_filter = config
+            .filters
+            .iter()
+            .flat_map(|filter| {
+                [
+                    Pubkey::from_str(&filter.mint_address)
+                        .expect("should parse mint address pubkey"),
+                    Pubkey::from_str(&filter.token_program)
+                        .expect("should parse token program pubkey"),
+                ]
+            })
+            .collect();
+
+        let rpc_http_url = config.rpc_http_url;
+        let filters = config.filters.clone();
+
+        self.token_account_filters = config.filters;
+        self.slot_topic = config.slot_topic;
+        self.transaction_topic = config.transaction_topic;
+        self.overflow_policy = config.channel_overflow_policy;
         // Start Pulsar client in separate thread
-        self.start_pulsar_client(pulsar_config)
+        self.start_pulsar_client(pulsar_config, config.channel_buffer_size)?;
+
+        // Bootstrap a snapshot of the currently matching token accounts so
+        // consumers have a complete initial view before live updates arrive.
+        if let Some(rpc_http_url) = rpc_http_url {
+            self.start_snapshot_bootstrap(rpc_http_url, filters);
+        }
+
+        Ok(())
     }
 
     fn on_unload(&mut self) {
         // Send shutdown message if sender exists
-        if let Some(sender) = &self.sender {
-            let _ = sender.send(PulsarMessage::Shutdown);
+        if let Some(channel) = &self.sender {
+            channel.send_blocking(PulsarMessage::Shutdown);
         }
 
         // Wait for Pulsar thread to finish
@@ -213,65 +867,165 @@ impl GeyserPlugin for CarrotPlugin {
             ),
         };
 
-        // there must be a cleaner way to compare these
-        // bytes without cloning or copying them.
-        let token_program_string = self
-            .token_account_filter
-            .as_ref()
-            .expect("should access token account filter")
-            .token_program
-            .clone();
-
-        let token_program_pubkey =
-            Pubkey::from_str(&token_program_string).expect("should parse token program pubkey");
-
-        // Not interested in this program
-        // (owner is the owning program here)
-        if owner_pubkey_bytes != token_program_pubkey.to_bytes() {
-            println!("skipping account update, owner is not token program");
-            return Ok(());
+        let pubkey = Pubkey::try_from(pubkey_bytes).expect("should construct account pubkey");
+
+        // Match the incoming account against every configured filter and
+        // collect the topics of the ones that match, so one account write
+        // can fan out to several topics (e.g. per-mint or per-program streams).
+        // The mint bytes are only read once an owner match confirms this is
+        // SPL-sized data; ordinary (e.g. system-owned) accounts can be much
+        // shorter than 32 bytes.
+        let mut matched_topics = Vec::new();
+        for filter in &self.token_account_filters {
+            let token_program_pubkey = Pubkey::from_str(&filter.token_program)
+                .expect("should parse token program pubkey");
+
+            // Not interested in this program
+            // (owner is the owning program here)
+            if owner_pubkey_bytes != token_program_pubkey.to_bytes() {
+                continue;
+            }
+
+            if account_bytes.len() < 32 {
+                continue;
+            }
+
+            let token_account_mint_bytes = array_ref![account_bytes, 0, 32];
+            let token_account_mint_pubkey = Pubkey::new_from_array(*token_account_mint_bytes);
+
+            let expected_mint_address_pubkey = Pubkey::from_str(&filter.mint_address)
+                .expect("should parse mint address pubkey");
+
+            if expected_mint_address_pubkey != token_account_mint_pubkey {
+                continue;
+            }
+
+            matched_topics.push(filter.topic.clone());
         }
 
-        let pubkey = Pubkey::try_from(pubkey_bytes).expect("should construct account pubkey");
+        if matched_topics.is_empty() {
+            println!("skipping account update, no filter matched");
+            return Ok(());
+        }
 
-        let token_account_mint_bytes = array_ref![account_bytes, 0, 32];
-        let token_account_mint_pubkey = Pubkey::new_from_array(*token_account_mint_bytes);
+        self.highest_write_slot.fetch_max(slot, Ordering::Relaxed);
 
-        let mint_address_string = self
-            .token_account_filter
-            .as_ref()
-            .expect("should access token account filter")
-            .mint_address
-            .clone();
+        let is_newer = self
+            .chain_data
+            .lock()
+            .expect("chain data lock should not be poisoned")
+            .observe_account_write(pubkey, slot, write_version, &account_bytes);
 
-        let expected_mint_address_pubkey =
-            Pubkey::from_str(&mint_address_string).expect("should parse mint address pubkey");
+        if !is_newer {
+            println!(
+                "skipping account update, stale write: pubkey={}, slot={}, write_version={}",
+                pubkey, slot, write_version
+            );
+            return Ok(());
+        }
 
-        if expected_mint_address_pubkey != token_account_mint_pubkey {
-            println!("Mint pubkey does not match expected mint pubkey");
+        if account_bytes.len() < 64 {
+            println!("skipping account update, data too short to read owner bytes");
             return Ok(());
         }
 
         let token_account_owner_bytes = array_ref![account_bytes, 32, 32];
         let token_account_owner_pubkey = Pubkey::new_from_array(*token_account_owner_bytes);
 
-        // Send account update to Pulsar thread if sender exists
-        if let Some(sender) = &self.sender {
-            if let Err(e) = sender.send(PulsarMessage::AccountUpdate {
-                pubkey,
-                slot,
-                token_account_owner: token_account_owner_pubkey,
-                account_data: account_bytes.into(),
-                write_version,
-            }) {
-                eprintln!("Failed to send account update to Pulsar thread: {:?}", e);
-            }
-        }
+        // Send account update to Pulsar thread, honoring the configured
+        // overflow policy.
+        self.dispatch(PulsarMessage::AccountUpdate {
+            pubkey,
+            slot,
+            token_account_owner: token_account_owner_pubkey,
+            account_data: account_bytes.into(),
+            write_version,
+            topics: matched_topics,
+            is_snapshot: false,
+        });
 
         println!("Account update: pubkey={}, slot={}", pubkey, slot);
         Ok(())
     }
 
+    fn update_slot_status(
+        &self,
+        slot: u64,
+        parent: Option<u64>,
+        status: SlotStatus,
+    ) -> PluginResult<()> {
+        let abandoned_slots = self
+            .chain_data
+            .lock()
+            .expect("chain data lock should not be poisoned")
+            .observe_slot_status(slot, parent, status);
+
+        // Drives commit/abort of the per-slot Pulsar transaction regardless
+        // of whether a slot_topic is configured.
+        self.dispatch(PulsarMessage::SlotTransaction {
+            slot,
+            status,
+            abandoned_slots,
+        });
+
+        let Some(topic) = &self.slot_topic else {
+            return Ok(());
+        };
+
+        self.dispatch(PulsarMessage::SlotUpdate {
+            slot,
+            parent,
+            status,
+            topic: topic.clone(),
+        });
+
+        Ok(())
+    }
+
+    fn notify_transaction(
+        &self,
+        transaction: ReplicaTransactionInfoVersions,
+        slot: u64,
+    ) -> PluginResult<()> {
+        let Some(topic) = &self.transaction_topic else {
+            return Ok(());
+        };
+
+        let (signature, account_keys, is_success) = match transaction {
+            ReplicaTransactionInfoVersions::V0_0_1(info) => (
+                info.signature.to_string(),
+                info.transaction.message().account_keys().iter().copied().collect::<Vec<Pubkey>>(),
+                info.transaction_status_meta.status.is_ok(),
+            ),
+            ReplicaTransactionInfoVersions::V0_0_2(info) => (
+                info.signature.to_string(),
+                info.transaction.message().account_keys().iter().copied().collect::<Vec<Pubkey>>(),
+                info.transaction_status_meta.status.is_ok(),
+            ),
+        };
+
+        // Only forward transactions that touch one of the configured mints
+        // or token programs; with no filters configured, every transaction
+        // is forwarded.
+        if !self.transaction_account_filter.is_empty()
+            && !account_keys
+                .iter()
+                .any(|key| self.transaction_account_filter.contains(key))
+        {
+            return Ok(());
+        }
+
+        self.dispatch(PulsarMessage::Transaction {
+            signature,
+            slot,
+            account_keys,
+            is_success,
+            topic: topic.clone(),
+        });
+
+        Ok(())
+    }
+
     fn notify_end_of_startup(&self) -> PluginResult<()> {
         Ok(())
     }
@@ -281,6 +1035,120 @@ impl GeyserPlugin for CarrotPlugin {
     }
 
     fn transaction_notifications_enabled(&self) -> bool {
-        false
+        self.transaction_topic.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pubkey(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    #[test]
+    fn observe_account_write_rejects_stale_writes() {
+        let mut chain_data = ChainData::default();
+        let key = pubkey(1);
+
+        assert!(chain_data.observe_account_write(key, 10, 0, b"v1"));
+        // Older slot: stale, rejected.
+        assert!(!chain_data.observe_account_write(key, 9, 5, b"v0"));
+        // Same slot, same write_version: duplicate, rejected.
+        assert!(!chain_data.observe_account_write(key, 10, 0, b"v1-dup"));
+        // Same slot, higher write_version: newer, accepted.
+        assert!(chain_data.observe_account_write(key, 10, 1, b"v2"));
+        // Newer slot: accepted.
+        assert!(chain_data.observe_account_write(key, 11, 0, b"v3"));
+    }
+
+    #[test]
+    fn prune_non_ancestors_drops_forked_sibling_on_root() {
+        let mut chain_data = ChainData::default();
+
+        // Fork at slot 1: slot 2 and slot 3 are competing children.
+        chain_data.observe_slot_status(1, None, SlotStatus::Rooted);
+        chain_data.observe_slot_status(2, Some(1), SlotStatus::Processed);
+        chain_data.observe_slot_status(3, Some(1), SlotStatus::Processed);
+
+        let winner = pubkey(1);
+        let loser = pubkey(2);
+        chain_data.observe_account_write(winner, 2, 0, b"winning fork");
+        chain_data.observe_account_write(loser, 3, 0, b"losing fork");
+
+        // Slot 2 is rooted: slot 3 (sibling, non-ancestor) must be pruned
+        // along with its buffered account write, while slot 2's write (and
+        // its ancestor slot 1) survive.
+        let abandoned = chain_data.observe_slot_status(2, Some(1), SlotStatus::Rooted);
+
+        assert_eq!(abandoned, vec![3]);
+        assert!(chain_data.accounts.contains_key(&winner));
+        assert!(!chain_data.accounts.contains_key(&loser));
+        assert!(chain_data.slots.contains_key(&1));
+        assert!(chain_data.slots.contains_key(&2));
+        assert!(!chain_data.slots.contains_key(&3));
+    }
+
+    fn account_update(slot: u64) -> PulsarMessage {
+        PulsarMessage::AccountUpdate {
+            pubkey: pubkey(1),
+            token_account_owner: pubkey(2),
+            account_data: Vec::new(),
+            write_version: 0,
+            slot,
+            topics: vec!["topic".to_string()],
+            is_snapshot: false,
+        }
+    }
+
+    #[test]
+    fn send_drop_oldest_evicts_the_oldest_evictable_message_first() {
+        let channel = BoundedChannel::new(2);
+
+        assert!(!channel.send_drop_oldest(account_update(1)));
+        assert!(!channel.send_drop_oldest(account_update(2)));
+        // Channel is full; the update for slot 1 is the oldest evictable
+        // entry and should be dropped to make room for slot 3's update.
+        assert!(channel.send_drop_oldest(account_update(3)));
+
+        assert_eq!(
+            channel.recv(),
+            PulsarMessage::AccountUpdate {
+                pubkey: pubkey(1),
+                token_account_owner: pubkey(2),
+                account_data: Vec::new(),
+                write_version: 0,
+                slot: 2,
+                topics: vec!["topic".to_string()],
+                is_snapshot: false,
+            }
+        );
+        assert!(channel.take_dropped_slot(1));
+        assert!(!channel.take_dropped_slot(2));
+    }
+
+    #[test]
+    fn send_drop_oldest_never_evicts_rooted_slot_transaction() {
+        let channel = BoundedChannel::new(1);
+
+        assert!(!channel.send_drop_oldest(PulsarMessage::SlotTransaction {
+            slot: 1,
+            status: SlotStatus::Rooted,
+            abandoned_slots: Vec::new(),
+        }));
+
+        // Nothing evictable is buffered, so the incoming message is dropped
+        // instead of the rooted SlotTransaction already in the queue.
+        assert!(channel.send_drop_oldest(account_update(2)));
+
+        assert_eq!(
+            channel.recv(),
+            PulsarMessage::SlotTransaction {
+                slot: 1,
+                status: SlotStatus::Rooted,
+                abandoned_slots: Vec::new(),
+            }
+        );
     }
 }